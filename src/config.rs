@@ -0,0 +1,163 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::UserAction;
+
+const CONFIG_FILE_NAME: &str = "config.json";
+const LIST_CONTEXT: &str = "List";
+
+#[derive(Deserialize, Debug)]
+struct AppConfig {
+    #[serde(default)]
+    keybindings: HashMap<String, HashMap<String, UserAction>>,
+    #[serde(default = "default_show_banner")]
+    show_banner: bool,
+}
+
+fn default_show_banner() -> bool {
+    true
+}
+
+fn config_file_path() -> PathBuf {
+    crate::get_base_path().join(CONFIG_FILE_NAME)
+}
+
+fn load_app_config() -> Option<AppConfig> {
+    let data = fs::read_to_string(config_file_path()).ok()?;
+    serde_json::from_str(&data).ok()
+}
+
+/// Diz se o banner embutido no TUI deve ser exibido além da notificação do
+/// sistema, conforme a flag `show_banner` do arquivo de configuração.
+pub fn show_banner_enabled() -> bool {
+    load_app_config().map(|c| c.show_banner).unwrap_or(true)
+}
+
+/// Carrega os atalhos de teclado do arquivo de configuração, se existir,
+/// caindo para os atalhos padrão quando o arquivo não existe ou é inválido.
+pub fn load_keybindings() -> HashMap<KeyEvent, UserAction> {
+    match load_app_config() {
+        Some(config) => match config.keybindings.get(LIST_CONTEXT) {
+            Some(bindings) => parse_bindings(bindings),
+            None => default_keybindings(),
+        },
+        None => default_keybindings(),
+    }
+}
+
+fn parse_bindings(bindings: &HashMap<String, UserAction>) -> HashMap<KeyEvent, UserAction> {
+    let mut map = HashMap::new();
+    for (key_str, action) in bindings {
+        if let Some(key_event) = parse_key(key_str) {
+            map.insert(key_event, action.clone());
+        }
+    }
+    map
+}
+
+/// Os atalhos padrão, usados quando nenhum arquivo de configuração é encontrado.
+pub fn default_keybindings() -> HashMap<KeyEvent, UserAction> {
+    let mut map = HashMap::new();
+    map.insert(KeyEvent::new(KeyCode::Up, KeyModifiers::NONE), UserAction::MoveUp);
+    map.insert(KeyEvent::new(KeyCode::Char('k'), KeyModifiers::NONE), UserAction::MoveUp);
+    map.insert(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE), UserAction::MoveDown);
+    map.insert(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE), UserAction::MoveDown);
+    map.insert(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE), UserAction::Quit);
+    map.insert(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE), UserAction::Quit);
+    map.insert(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE), UserAction::Open);
+    map.insert(KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE), UserAction::Delete);
+    map.insert(KeyEvent::new(KeyCode::Char('?'), KeyModifiers::NONE), UserAction::ToggleKeybinds);
+    map.insert(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::NONE), UserAction::CycleCategory);
+    map.insert(KeyEvent::new(KeyCode::Char('/'), KeyModifiers::NONE), UserAction::Search);
+    map
+}
+
+/// Converte uma string de atalho como `"<q>"`, `"<Ctrl-d>"` ou `"<esc>"` em um `KeyEvent`.
+fn parse_key(raw: &str) -> Option<KeyEvent> {
+    let inner = raw.strip_prefix('<')?.strip_suffix('>')?;
+    let mut parts: Vec<&str> = inner.split('-').collect();
+    let key_part = parts.pop()?;
+
+    let mut modifiers = KeyModifiers::NONE;
+    for modifier in parts {
+        match modifier.to_lowercase().as_str() {
+            "ctrl" => modifiers |= KeyModifiers::CONTROL,
+            "alt" => modifiers |= KeyModifiers::ALT,
+            "shift" => modifiers |= KeyModifiers::SHIFT,
+            _ => return None,
+        }
+    }
+
+    let code = match key_part.to_lowercase().as_str() {
+        "esc" => KeyCode::Esc,
+        "enter" => KeyCode::Enter,
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "tab" => KeyCode::Tab,
+        "backspace" => KeyCode::Backspace,
+        "space" => KeyCode::Char(' '),
+        single if single.chars().count() == 1 => {
+            KeyCode::Char(single.chars().next().unwrap())
+        }
+        _ => return None,
+    };
+
+    Some(KeyEvent::new(code, modifiers))
+}
+
+/// Monta o texto do painel de atalhos a partir dos bindings ativos.
+pub fn keybinds_text(keybinds: &HashMap<KeyEvent, UserAction>) -> String {
+    let mut entries: Vec<(String, &UserAction)> = keybinds
+        .iter()
+        .map(|(key, action)| (describe_key(key), action))
+        .collect();
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+    entries
+        .into_iter()
+        .map(|(key, action)| format!("{}: {}", key, describe_action(action)))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn describe_key(key: &KeyEvent) -> String {
+    let mut parts = Vec::new();
+    if key.modifiers.contains(KeyModifiers::CONTROL) {
+        parts.push("Ctrl".to_string());
+    }
+    if key.modifiers.contains(KeyModifiers::ALT) {
+        parts.push("Alt".to_string());
+    }
+    if key.modifiers.contains(KeyModifiers::SHIFT) {
+        parts.push("Shift".to_string());
+    }
+    let key_name = match key.code {
+        KeyCode::Char(c) => c.to_string(),
+        KeyCode::Up => "↑".to_string(),
+        KeyCode::Down => "↓".to_string(),
+        KeyCode::Esc => "Esc".to_string(),
+        KeyCode::Enter => "Enter".to_string(),
+        other => format!("{:?}", other),
+    };
+    parts.push(key_name);
+    parts.join("-")
+}
+
+fn describe_action(action: &UserAction) -> &'static str {
+    match action {
+        UserAction::MoveUp => "Mover para cima",
+        UserAction::MoveDown => "Mover para baixo",
+        UserAction::Quit => "Sair",
+        UserAction::Open => "Abrir nota",
+        UserAction::Delete => "Deletar nota",
+        UserAction::ToggleKeybinds => "Mostrar teclas de atalho",
+        UserAction::CycleCategory => "Filtrar por categoria",
+        UserAction::Search => "Buscar notas",
+        UserAction::None => "",
+    }
+}
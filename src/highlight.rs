@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+
+use syntect::easy::HighlightLines;
+use syntect::highlighting::{Style as SyntectStyle, Theme, ThemeSet};
+use syntect::parsing::SyntaxSet;
+use syntect::util::LinesWithEndings;
+use tui::style::{Color, Style};
+use tui::text::{Span, Spans};
+
+use crate::Note;
+
+/// Realça o Markdown (incluindo blocos de código) de uma nota usando `syntect`,
+/// mantendo em cache a saída já convertida para `Spans` por nota.
+pub struct MarkdownHighlighter {
+    syntax_set: SyntaxSet,
+    theme: Theme,
+    cache: HashMap<(Option<String>, String), Vec<Spans<'static>>>,
+}
+
+impl MarkdownHighlighter {
+    pub fn new() -> Self {
+        let syntax_set = SyntaxSet::load_defaults_newlines();
+        let theme_set = ThemeSet::load_defaults();
+        let theme = theme_set.themes["base16-ocean.dark"].clone();
+        Self {
+            syntax_set,
+            theme,
+            cache: HashMap::new(),
+        }
+    }
+
+    /// Retorna as linhas realçadas de uma nota, calculando-as na primeira vez
+    /// e reaproveitando o resultado em navegações seguintes. A chave do cache
+    /// inclui a categoria, já que título e categoria juntos identificam a nota.
+    pub fn highlight(&mut self, note: &Note) -> &[Spans<'static>] {
+        let key = (note.category.clone(), note.title.clone());
+        if !self.cache.contains_key(&key) {
+            let rendered = self.render(&note.content);
+            self.cache.insert(key.clone(), rendered);
+        }
+        self.cache.get(&key).unwrap()
+    }
+
+    /// Descarta o cache de uma nota específica, usado quando seu conteúdo é
+    /// atualizado em disco (por exemplo, após editá-la pelo `$EDITOR`).
+    pub fn invalidate(&mut self, category: Option<&str>, title: &str) {
+        self.cache
+            .remove(&(category.map(str::to_string), title.to_string()));
+    }
+
+    /// Descarta todo o cache, usado quando as notas são recarregadas do disco
+    /// por um evento externo (o watcher não sabe quais notas mudaram).
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+
+    fn render(&self, content: &str) -> Vec<Spans<'static>> {
+        let syntax = self
+            .syntax_set
+            .find_syntax_by_extension("md")
+            .unwrap_or_else(|| self.syntax_set.find_syntax_plain_text());
+        let mut highlighter = HighlightLines::new(syntax, &self.theme);
+
+        LinesWithEndings::from(content)
+            .map(|line| {
+                let ranges = highlighter
+                    .highlight_line(line, &self.syntax_set)
+                    .unwrap_or_default();
+                let spans: Vec<Span<'static>> = ranges
+                    .into_iter()
+                    .map(|(style, text)| {
+                        Span::styled(
+                            text.trim_end_matches('\n').to_string(),
+                            syntect_style_to_tui(style),
+                        )
+                    })
+                    .collect();
+                Spans::from(spans)
+            })
+            .collect()
+    }
+}
+
+fn syntect_style_to_tui(style: SyntectStyle) -> Style {
+    Style::default().fg(Color::Rgb(
+        style.foreground.r,
+        style.foreground.g,
+        style.foreground.b,
+    ))
+}
@@ -1,24 +1,59 @@
+mod config;
+mod highlight;
+mod notifier;
+mod search;
+mod watcher;
+
 use chrono::{DateTime, Utc};
 use clap::{App, Arg, SubCommand};
 use crossterm::event::{self, KeyCode, KeyEvent};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
 use std::{fs, process::Command};
 use tui::{
     backend::CrosstermBackend,
     layout::{Constraint, Direction, Layout},
     style::{Color, Modifier, Style},
+    text::Text,
     widgets::{Block, Borders, List, ListItem, Paragraph},
     Terminal,
 };
 
-const NOTES_DIR: &str = "notes";
-const NOTES_JSON_FILE: &str = "notes/notes.json";
+const APP_DIR_NAME: &str = "notecli";
+const NOTES_JSON_FILE_NAME: &str = "notes.json";
 
 #[derive(Serialize, Deserialize, Debug)]
 struct Note {
     title: String,
     content: String,
     date: DateTime<Utc>,
+    category: Option<String>,
+}
+
+/// Resolve o diretório base de dados do notecli, honrando `XDG_DATA_HOME`
+/// e caindo para `$HOME/.local/share/notecli` quando a variável não está definida.
+fn get_base_path() -> PathBuf {
+    if let Ok(xdg_data_home) = std::env::var("XDG_DATA_HOME") {
+        PathBuf::from(xdg_data_home).join(APP_DIR_NAME)
+    } else {
+        let home = std::env::var("HOME").expect("HOME não definido");
+        PathBuf::from(home).join(".local/share").join(APP_DIR_NAME)
+    }
+}
+
+fn notes_json_path() -> PathBuf {
+    get_base_path().join(NOTES_JSON_FILE_NAME)
+}
+
+/// Calcula o caminho do arquivo `.md` de uma nota, considerando sua categoria opcional.
+fn note_file_path(title: &str, category: Option<&str>) -> PathBuf {
+    let mut path = get_base_path();
+    if let Some(category) = category {
+        path.push(category);
+    }
+    path.push(format!("{}.md", title));
+    path
 }
 
 fn main() {
@@ -34,6 +69,13 @@ fn main() {
                         .help("O título da nota")
                         .required(true)
                         .index(1),
+                )
+                .arg(
+                    Arg::with_name("category")
+                        .long("category")
+                        .short("c")
+                        .help("Categoria opcional para organizar a nota")
+                        .takes_value(true),
                 ),
         )
         .subcommand(SubCommand::with_name("list").about("Lista as notas"))
@@ -43,23 +85,27 @@ fn main() {
         Some("new") => {
             let sub_matches = matches.subcommand_matches("new").unwrap();
             let title = sub_matches.value_of("TITLE").unwrap().to_string();
-            create_new_note(&title);
+            let category = sub_matches.value_of("category").map(|c| c.to_string());
+            create_new_note(&title, category);
         }
         Some("list") => list_notes(),
         _ => println!("Comando não reconhecido."),
     }
 }
 
-fn ensure_notes_directory_exists() {
-    let path = std::path::Path::new(NOTES_DIR);
+fn ensure_notes_directory_exists(category: Option<&str>) {
+    let mut path = get_base_path();
+    if let Some(category) = category {
+        path.push(category);
+    }
     if !path.exists() {
-        fs::create_dir(path).expect("Erro ao criar o diretório 'notes'");
+        fs::create_dir_all(&path).expect("Erro ao criar o diretório de notas");
     }
 }
 
-fn create_new_note(title: &str) {
-    ensure_notes_directory_exists();
-    let file_path = format!("notes/{}.md", title);
+fn create_new_note(title: &str, category: Option<String>) {
+    ensure_notes_directory_exists(category.as_deref());
+    let file_path = note_file_path(title, category.as_deref());
     let editor = std::env::var("EDITOR").unwrap_or("vim".to_string());
     Command::new(editor)
         .arg(&file_path)
@@ -71,16 +117,18 @@ fn create_new_note(title: &str) {
         title: title.to_string(),
         content,
         date: Utc::now(),
+        category,
     };
 
     save_note(note);
 }
 
 fn save_note(note: Note) {
-    ensure_notes_directory_exists();
+    ensure_notes_directory_exists(note.category.as_deref());
 
-    let mut notes: Vec<Note> = if fs::read_to_string(NOTES_JSON_FILE).is_ok() {
-        let data = fs::read_to_string(NOTES_JSON_FILE).expect("Erro ao ler o arquivo JSON");
+    let notes_file = notes_json_path();
+    let mut notes: Vec<Note> = if fs::read_to_string(&notes_file).is_ok() {
+        let data = fs::read_to_string(&notes_file).expect("Erro ao ler o arquivo JSON");
         serde_json::from_str(&data).expect("Erro ao desserializar as notas")
     } else {
         Vec::new()
@@ -89,9 +137,12 @@ fn save_note(note: Note) {
     notes.push(note);
 
     let json = serde_json::to_string(&notes).expect("Erro ao serializar a nota");
-    fs::write(NOTES_JSON_FILE, json).expect("Erro ao escrever no arquivo JSON");
+    fs::write(&notes_file, json).expect("Erro ao escrever no arquivo JSON");
+
+    notifier::Notifier::new().notify("Nota criada com sucesso!");
 }
 
+#[derive(Clone, Debug, Deserialize)]
 enum UserAction {
     MoveUp,
     MoveDown,
@@ -99,40 +150,36 @@ enum UserAction {
     Open,
     Delete,
     ToggleKeybinds,
+    CycleCategory,
+    Search,
     None,
 }
 
-fn handle_user_input() -> UserAction {
+fn handle_user_input(keybinds: &HashMap<KeyEvent, UserAction>) -> UserAction {
     if let Ok(event) = event::read() {
-        if let event::Event::Key(KeyEvent { code, .. }) = event {
-            match code {
-                KeyCode::Up | KeyCode::Char('k') => return UserAction::MoveUp,
-                KeyCode::Down | KeyCode::Char('j') => return UserAction::MoveDown,
-                KeyCode::Char('q') | KeyCode::Esc => return UserAction::Quit,
-                KeyCode::Enter => return UserAction::Open,
-                KeyCode::Char('x') => return UserAction::Delete,
-                KeyCode::Char('?') => return UserAction::ToggleKeybinds,
-                _ => return UserAction::None,
-            }
+        if let event::Event::Key(key_event) = event {
+            return keybinds
+                .get(&key_event)
+                .cloned()
+                .unwrap_or(UserAction::None);
         }
     }
     UserAction::None
 }
 
-const KEYBINDS_TEXT: &str = "\
-    ↑/k: Mover para cima
-    ↓/j: Mover para baixo
-    Enter: Abrir nota
-    x: Deletar nota
-    ?: Mostrar teclas de atalho 
-    q/Esc: Sair";
-
 fn display_tui(mut notes: Vec<Note>) {
     let backend = CrosstermBackend::new(std::io::stdout());
     let mut terminal = Terminal::new(backend).unwrap();
 
     let _ = crossterm::terminal::enable_raw_mode();
     let mut show_keybinds = true;
+    let keybinds = config::load_keybindings();
+    let keybinds_text = config::keybinds_text(&keybinds);
+    let mut highlighter = highlight::MarkdownHighlighter::new();
+    let notifier = notifier::Notifier::new();
+    // Mantido vivo até o fim da função: o watcher para de observar quando é descartado.
+    let watcher_handle = watcher::watch_notes_dir(&get_base_path()).ok();
+    let watcher_rx = watcher_handle.as_ref().map(|(_, rx)| rx);
 
     let selected_style = Style::default()
         .fg(tui::style::Color::LightMagenta)
@@ -143,8 +190,41 @@ fn display_tui(mut notes: Vec<Note>) {
     terminal.clear().unwrap();
 
     let mut selected_index = 0;
+    let mut category_filter = CategoryFilter::All;
+    let mut search_mode = false;
+    let mut search_query = String::new();
+    let mut visible: Vec<usize> = Vec::new();
 
     loop {
+        let changed_on_disk = watcher_rx
+            .map(|rx| rx.try_iter().count() > 0)
+            .unwrap_or(false);
+        let mut reselect_title = None;
+        if changed_on_disk {
+            reselect_title = visible.get(selected_index).map(|&i| notes[i].title.clone());
+            if let Some(reloaded) = reload_notes() {
+                notes = reloaded;
+                // O watcher não diz quais notas mudaram; descarta tudo para
+                // não exibir conteúdo realçado desatualizado.
+                highlighter.clear();
+            }
+        }
+
+        let categories = distinct_categories(&notes);
+        visible = search::filter_notes(&notes, &search_query)
+            .into_iter()
+            .filter(|&i| category_filter.matches(&notes[i]))
+            .collect();
+
+        if let Some(title) = reselect_title {
+            if let Some(pos) = visible.iter().position(|&i| notes[i].title == title) {
+                selected_index = pos;
+            }
+        }
+        if selected_index >= visible.len() && !visible.is_empty() {
+            selected_index = visible.len() - 1;
+        }
+
         terminal
             .draw(|f| {
                 let main_chunks = Layout::default()
@@ -156,81 +236,202 @@ fn display_tui(mut notes: Vec<Note>) {
                     .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
                     .split(main_chunks[0]);
 
-                let titles: Vec<ListItem> = notes
+                let titles: Vec<ListItem> = visible
                     .iter()
                     .enumerate()
-                    .map(|(i, note)| {
+                    .map(|(visible_i, &note_i)| {
+                        let note = &notes[note_i];
                         let title_with_date =
                             format!("{} - {}", note.title, note.date.format("%Y-%m-%d"));
-                        if i == selected_index {
+                        if visible_i == selected_index {
                             ListItem::new(title_with_date).style(selected_style)
                         } else {
                             ListItem::new(title_with_date).style(normal_style)
                         }
                     })
                     .collect();
-                let selected_content = &notes[selected_index].content;
 
-                let list =
-                    List::new(titles).block(Block::default().borders(Borders::ALL).title("Notas"));
-                let content = Paragraph::new(selected_content.as_str())
-                    .block(Block::default().borders(Borders::ALL).title("Conteúdo"));
+                let category_label = category_filter.label();
+                let list_title = if search_mode || !search_query.is_empty() {
+                    format!("Notas [{}] /{}", category_label, search_query)
+                } else {
+                    format!("Notas [{}]", category_label)
+                };
+                let list = List::new(titles)
+                    .block(Block::default().borders(Borders::ALL).title(list_title));
+
+                let content = if let Some(&note_i) = visible.get(selected_index) {
+                    let lines = highlighter.highlight(&notes[note_i]);
+                    Paragraph::new(Text::from(lines.to_vec()))
+                } else {
+                    Paragraph::new(Text::from(""))
+                }
+                .block(Block::default().borders(Borders::ALL).title("Conteúdo"));
 
                 f.render_widget(list, upper_chunks[0]);
                 f.render_widget(content, upper_chunks[1]);
                 if show_keybinds {
-                    let keybinds = Paragraph::new(KEYBINDS_TEXT).style(keybinds_style).block(
-                        Block::default()
-                            .borders(Borders::ALL)
-                            .title("Teclas de Atalho"),
-                    );
-                    f.render_widget(keybinds, main_chunks[1]);
+                    let keybinds_panel =
+                        Paragraph::new(keybinds_text.as_str())
+                            .style(keybinds_style)
+                            .block(
+                                Block::default()
+                                    .borders(Borders::ALL)
+                                    .title("Teclas de Atalho"),
+                            );
+                    f.render_widget(keybinds_panel, main_chunks[1]);
                 }
             })
             .unwrap();
 
-        match handle_user_input() {
+        // Espera por uma tecla com timeout curto em vez de bloquear, para que o
+        // loop também acorde periodicamente e perceba eventos do watcher.
+        if !event::poll(std::time::Duration::from_millis(200)).unwrap_or(false) {
+            continue;
+        }
+
+        if search_mode {
+            if let Ok(event::Event::Key(KeyEvent { code, .. })) = event::read() {
+                match code {
+                    KeyCode::Esc | KeyCode::Enter => search_mode = false,
+                    KeyCode::Backspace => {
+                        search_query.pop();
+                        selected_index = 0;
+                    }
+                    KeyCode::Char(c) => {
+                        search_query.push(c);
+                        selected_index = 0;
+                    }
+                    _ => {}
+                }
+            }
+            continue;
+        }
+
+        match handle_user_input(&keybinds) {
             UserAction::MoveUp => {
                 if selected_index > 0 {
                     selected_index -= 1;
                 }
             }
             UserAction::MoveDown => {
-                if selected_index < notes.len() - 1 {
+                if selected_index + 1 < visible.len() {
                     selected_index += 1;
                 }
             }
             UserAction::Quit => break,
             UserAction::Open => {
-                let note_path = format!("notes/{}.md", notes[selected_index].title);
-                let editor = std::env::var("EDITOR").unwrap_or("nano".to_string());
-                Command::new(editor)
-                    .arg(&note_path)
-                    .status()
-                    .expect("Falha ao abrir o editor");
+                if let Some(&note_i) = visible.get(selected_index) {
+                    let title = notes[note_i].title.clone();
+                    let category = notes[note_i].category.clone();
+                    let note_path = note_file_path(&title, category.as_deref());
+                    let editor = std::env::var("EDITOR").unwrap_or("nano".to_string());
+                    Command::new(editor)
+                        .arg(&note_path)
+                        .status()
+                        .expect("Falha ao abrir o editor");
+
+                    // O $EDITOR pode ter alterado o conteúdo; relê o arquivo e
+                    // persiste em notes.json para que o watcher veja a mudança.
+                    if let Ok(updated_content) = fs::read_to_string(&note_path) {
+                        notes[note_i].content = updated_content;
+                        persist_notes(&notes);
+                        highlighter.invalidate(category.as_deref(), &title);
+                        notifier.notify("Nota salva com sucesso!");
+                    }
+                }
             }
 
             UserAction::Delete => {
-                delete_note(&mut notes, selected_index);
-                if selected_index >= notes.len() && selected_index > 0 {
-                    selected_index -= 1;
+                if let Some(&note_i) = visible.get(selected_index) {
+                    delete_note(&mut notes, note_i);
+                    notifier.notify("Nota deletada com sucesso!");
+                    if notifier.show_banner() {
+                        let success_style = Style::default().fg(tui::style::Color::Green);
+                        show_message(&mut terminal, "Nota deletada com sucesso!", success_style);
+                    }
                 }
-                let success_style = Style::default().fg(tui::style::Color::Green);
-                show_message(&mut terminal, "Nota deletada com sucesso!", success_style);
             }
             UserAction::ToggleKeybinds => {
                 show_keybinds = !show_keybinds;
             }
+            UserAction::CycleCategory => {
+                category_filter = next_category_filter(&category_filter, &categories);
+                selected_index = 0;
+            }
+            UserAction::Search => {
+                search_mode = true;
+            }
             UserAction::None => {}
         }
     }
     let _ = crossterm::terminal::disable_raw_mode();
 }
 
+/// O filtro de categoria ativo na lista de notas do TUI.
+#[derive(Clone, Debug, PartialEq)]
+enum CategoryFilter {
+    All,
+    Uncategorized,
+    Category(String),
+}
+
+impl CategoryFilter {
+    fn matches(&self, note: &Note) -> bool {
+        match self {
+            CategoryFilter::All => true,
+            CategoryFilter::Uncategorized => note.category.is_none(),
+            CategoryFilter::Category(category) => note.category.as_deref() == Some(category),
+        }
+    }
+
+    fn label(&self) -> &str {
+        match self {
+            CategoryFilter::All => "Todas",
+            CategoryFilter::Uncategorized => "Sem categoria",
+            CategoryFilter::Category(category) => category,
+        }
+    }
+}
+
+/// Retorna a lista ordenada e sem duplicatas de categorias presentes nas notas.
+fn distinct_categories(notes: &[Note]) -> Vec<String> {
+    let mut categories: Vec<String> = notes
+        .iter()
+        .filter_map(|note| note.category.clone())
+        .collect();
+    categories.sort();
+    categories.dedup();
+    categories
+}
+
+/// Avança o filtro de categoria: Todas -> Sem categoria -> cada categoria -> Todas.
+fn next_category_filter(current: &CategoryFilter, categories: &[String]) -> CategoryFilter {
+    match current {
+        CategoryFilter::All => CategoryFilter::Uncategorized,
+        CategoryFilter::Uncategorized => categories
+            .first()
+            .cloned()
+            .map(CategoryFilter::Category)
+            .unwrap_or(CategoryFilter::All),
+        CategoryFilter::Category(category) => match categories.iter().position(|c| c == category) {
+            Some(i) if i + 1 < categories.len() => CategoryFilter::Category(categories[i + 1].clone()),
+            _ => CategoryFilter::All,
+        },
+    }
+}
+
+/// Relê `notes.json` do disco, usada para atualizar o estado do TUI quando o
+/// watcher do sistema de arquivos detecta uma mudança.
+fn reload_notes() -> Option<Vec<Note>> {
+    let data = fs::read_to_string(notes_json_path()).ok()?;
+    serde_json::from_str(&data).ok()
+}
+
 fn list_notes() {
-    let notes_file = "notes/notes.json";
+    let notes_file = notes_json_path();
 
-    match fs::read_to_string(notes_file) {
+    match fs::read_to_string(&notes_file) {
         Ok(data) => match serde_json::from_str::<Vec<Note>>(&data) {
             Ok(notes) => display_tui(notes),
             Err(e) => {
@@ -238,7 +439,7 @@ fn list_notes() {
             }
         },
         Err(e) => {
-            println!("Erro ao ler o arquivo {}: {:?}", notes_file, e);
+            println!("Erro ao ler o arquivo {:?}: {:?}", notes_file, e);
         }
     }
 }
@@ -265,14 +466,19 @@ fn delete_note(notes: &mut Vec<Note>, index: usize) {
     if index < notes.len() {
         let note = &notes[index];
         // Remover o arquivo físico
-        let path = format!("notes/{}.md", note.title);
+        let path = note_file_path(&note.title, note.category.as_deref());
         fs::remove_file(path).expect("Falha ao deletar o arquivo da nota");
 
         // Remover a nota do vetor
         notes.remove(index);
 
-        let notes_file = "notes/notes.json";
-        let json = serde_json::to_string(&notes).expect("Erro ao serializar a nota");
-        fs::write(notes_file, json).expect("Erro ao escrever no arquivo JSON");
+        persist_notes(notes);
     }
 }
+
+/// Grava o estado atual das notas em `notes.json`.
+fn persist_notes(notes: &[Note]) {
+    let notes_file = notes_json_path();
+    let json = serde_json::to_string(&notes).expect("Erro ao serializar a nota");
+    fs::write(notes_file, json).expect("Erro ao escrever no arquivo JSON");
+}
@@ -0,0 +1,36 @@
+use notify_rust::Notification;
+
+use crate::config;
+
+/// Abstrai o envio de feedback ao usuário sobre ações em notas (criar,
+/// deletar, salvar) por notificações do sistema. O banner embutido do TUI é
+/// exibido adicionalmente apenas conforme a flag `show_banner` da
+/// configuração; uma falha do backend de notificações só é registrada.
+pub struct Notifier {
+    show_banner: bool,
+}
+
+impl Notifier {
+    pub fn new() -> Self {
+        Self {
+            show_banner: config::show_banner_enabled(),
+        }
+    }
+
+    /// Envia uma notificação de desktop com a mensagem informada, registrando
+    /// o erro e seguindo em frente quando não há um daemon de notificações.
+    pub fn notify(&self, message: &str) {
+        if let Err(e) = Notification::new()
+            .summary("notecli")
+            .body(message)
+            .show()
+        {
+            eprintln!("Falha ao enviar notificação do sistema: {:?}", e);
+        }
+    }
+
+    /// Se o banner embutido do TUI deve ser exibido além da notificação do sistema.
+    pub fn show_banner(&self) -> bool {
+        self.show_banner
+    }
+}
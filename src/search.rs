@@ -0,0 +1,145 @@
+use crate::Note;
+
+/// Calcula uma pontuação de correspondência fuzzy estilo Sublime Text para
+/// `query` dentro de `text`: casamento por subsequência, com bônus para
+/// caracteres consecutivos e para começos de palavra. Retorna `None` quando
+/// `query` não é uma subsequência de `text`.
+pub fn fuzzy_score(query: &str, text: &str) -> Option<i64> {
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let query_lower = query.to_lowercase();
+    let text_lower = text.to_lowercase();
+    let query_chars: Vec<char> = query_lower.chars().collect();
+    let text_chars: Vec<char> = text_lower.chars().collect();
+
+    let mut score: i64 = 0;
+    let mut query_i = 0;
+    let mut prev_matched_at: Option<usize> = None;
+
+    for (text_i, &ch) in text_chars.iter().enumerate() {
+        if query_i >= query_chars.len() {
+            break;
+        }
+        if ch != query_chars[query_i] {
+            continue;
+        }
+
+        score += 1;
+        if prev_matched_at == Some(text_i.wrapping_sub(1)) {
+            score += 5; // bônus por caracteres consecutivos
+        }
+        if text_i == 0 || text_chars[text_i - 1] == ' ' {
+            score += 3; // bônus por começo de palavra
+        }
+
+        prev_matched_at = Some(text_i);
+        query_i += 1;
+    }
+
+    if query_i == query_chars.len() {
+        Some(score)
+    } else {
+        None
+    }
+}
+
+/// Filtra e ordena os índices das notas cujo título ou conteúdo combinam com
+/// `query`, do melhor para o pior score. Título pesa mais que conteúdo.
+pub fn filter_notes(notes: &[Note], query: &str) -> Vec<usize> {
+    if query.is_empty() {
+        return (0..notes.len()).collect();
+    }
+
+    let mut scored: Vec<(usize, i64)> = notes
+        .iter()
+        .enumerate()
+        .filter_map(|(i, note)| {
+            let title_score = fuzzy_score(query, &note.title).map(|s| s * 2);
+            let content_score = fuzzy_score(query, &note.content);
+            match (title_score, content_score) {
+                (None, None) => None,
+                (a, b) => Some((i, a.unwrap_or(0) + b.unwrap_or(0))),
+            }
+        })
+        .filter(|(_, score)| *score > 0)
+        .collect();
+
+    scored.sort_by_key(|&(_, score)| std::cmp::Reverse(score));
+    scored.into_iter().map(|(i, _)| i).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn note(title: &str, content: &str) -> Note {
+        Note {
+            title: title.to_string(),
+            content: content.to_string(),
+            date: Utc::now(),
+            category: None,
+        }
+    }
+
+    #[test]
+    fn fuzzy_score_matches_subsequence() {
+        assert!(fuzzy_score("ntc", "notecli").is_some());
+    }
+
+    #[test]
+    fn fuzzy_score_rejects_non_subsequence() {
+        assert_eq!(fuzzy_score("xyz", "notecli"), None);
+    }
+
+    #[test]
+    fn fuzzy_score_empty_query_matches_everything() {
+        assert_eq!(fuzzy_score("", "anything"), Some(0));
+    }
+
+    #[test]
+    fn fuzzy_score_rewards_consecutive_matches() {
+        let consecutive = fuzzy_score("not", "notecli").unwrap();
+        let scattered = fuzzy_score("nti", "notecli").unwrap();
+        assert!(consecutive > scattered);
+    }
+
+    #[test]
+    fn fuzzy_score_rewards_word_boundary_matches() {
+        let boundary = fuzzy_score("cli", "note cli").unwrap();
+        let mid_word = fuzzy_score("cli", "notecliente").unwrap();
+        assert!(boundary > mid_word);
+    }
+
+    #[test]
+    fn filter_notes_with_empty_query_returns_all_in_order() {
+        let notes = vec![note("b", "..."), note("a", "...")];
+        assert_eq!(filter_notes(&notes, ""), vec![0, 1]);
+    }
+
+    #[test]
+    fn filter_notes_excludes_non_matches() {
+        let notes = vec![note("shopping list", "milk, eggs"), note("recipe", "pasta")];
+        assert_eq!(filter_notes(&notes, "recipe"), vec![1]);
+    }
+
+    #[test]
+    fn filter_notes_ranks_best_match_first() {
+        let notes = vec![
+            note("unrelated", "mentions rust in passing"),
+            note("rust notes", "all about rust"),
+        ];
+        assert_eq!(filter_notes(&notes, "rust"), vec![1, 0]);
+    }
+
+    #[test]
+    fn filter_notes_weighs_title_over_content() {
+        let notes = vec![
+            note("plain", "notecli is mentioned here"),
+            note("notecli", "nothing special here"),
+        ];
+        assert_eq!(filter_notes(&notes, "notecli"), vec![1, 0]);
+    }
+}
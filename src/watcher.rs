@@ -0,0 +1,13 @@
+use std::path::Path;
+use std::sync::mpsc::{channel, Receiver};
+
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
+
+/// Observa o diretório de notas (incluindo o `notes.json`) e devolve um
+/// receiver que sinaliza sempre que algo muda em disco.
+pub fn watch_notes_dir(path: &Path) -> notify::Result<(RecommendedWatcher, Receiver<notify::Result<Event>>)> {
+    let (tx, rx) = channel();
+    let mut watcher = notify::recommended_watcher(tx)?;
+    watcher.watch(path, RecursiveMode::Recursive)?;
+    Ok((watcher, rx))
+}